@@ -1,4 +1,4 @@
-use crate::sidecar::SidecarManager;
+use crate::sidecar::{ServerState, SidecarManager};
 use serde_json::{json, Value};
 use std::sync::Arc;
 use tauri::State;
@@ -15,7 +15,7 @@ pub async fn start_server(state: State<'_, AppState>) -> Result<(), String> {
 #[tauri::command]
 pub async fn stop_server(state: State<'_, AppState>) -> Result<(), String> {
     let manager = state.lock().await;
-    manager.stop()
+    manager.stop().await
 }
 
 #[tauri::command]
@@ -29,7 +29,7 @@ pub async fn reconnect_server(state: State<'_, AppState>) -> Result<(), String>
     let manager = state.lock().await;
 
     // Stop if running
-    let _ = manager.stop();
+    let _ = manager.stop().await;
 
     // Start fresh
     manager.start()?;
@@ -83,3 +83,40 @@ pub async fn mcp_get_auth_status(state: State<'_, AppState>) -> Result<Value, St
         "has_stored_credentials": status.has_stored_credentials,
     }))
 }
+
+#[tauri::command]
+pub async fn get_server_status(state: State<'_, AppState>) -> Result<ServerState, String> {
+    let manager = state.lock().await;
+    Ok(manager.get_status())
+}
+
+#[tauri::command]
+pub async fn mcp_get_logs(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let manager = state.lock().await;
+    Ok(manager.get_logs())
+}
+
+#[tauri::command]
+pub async fn mcp_clear_logs(state: State<'_, AppState>) -> Result<(), String> {
+    let manager = state.lock().await;
+    manager.clear_logs();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn mcp_call_tool(
+    state: State<'_, AppState>,
+    name: String,
+    args: Value,
+) -> Result<Value, String> {
+    // Clone the manager and release the lock before awaiting: a streaming tool call can run
+    // for a long time and must not block every other sidecar command for its duration.
+    let manager = state.lock().await.clone();
+    manager.call_tool(&name, args).await
+}
+
+#[tauri::command]
+pub async fn mcp_cancel_tool(state: State<'_, AppState>, request_id: String) -> Result<bool, String> {
+    let manager = state.lock().await;
+    Ok(manager.cancel_tool(&request_id))
+}
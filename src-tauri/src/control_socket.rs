@@ -0,0 +1,284 @@
+//! A local, same-user-only IPC endpoint so external tools (a companion CLI, a status bar
+//! widget, ...) can query and drive the sidecar without going through the Tauri window.
+//!
+//! The wire protocol is line-delimited JSON: each line is a request object with a `cmd`
+//! field, answered with a single JSON response line, e.g. `{"cmd":"status"}` ->
+//! `{"running":true,"state":"ready","authenticated":true}`.
+
+use crate::sidecar::SidecarManager;
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+
+/// Handle a single request line against the sidecar and return the JSON response to write back.
+async fn handle_line(manager: &SidecarManager, line: &str) -> Value {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(v) => v,
+        Err(e) => return json!({ "error": format!("invalid request: {}", e) }),
+    };
+
+    match request.get("cmd").and_then(Value::as_str) {
+        Some("status") => {
+            let authenticated = manager.get_auth_status().await.ok().map(|s| s.authenticated);
+            json!({
+                "running": manager.is_running(),
+                "state": manager.get_status(),
+                "authenticated": authenticated,
+            })
+        }
+        Some("reconnect") => {
+            let _ = manager.stop().await;
+            match manager.start() {
+                Ok(()) => match manager.wait_for_ready().await {
+                    Ok(()) => json!({ "ok": true }),
+                    Err(e) => json!({ "ok": false, "error": e }),
+                },
+                Err(e) => json!({ "ok": false, "error": e }),
+            }
+        }
+        Some(other) => json!({ "error": format!("unknown command: {}", other) }),
+        None => json!({ "error": "missing cmd" }),
+    }
+}
+
+#[cfg(unix)]
+mod imp {
+    use super::handle_line;
+    use crate::sidecar::SidecarManager;
+    use std::path::PathBuf;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{UnixListener, UnixStream};
+    use tokio::sync::oneshot;
+
+    /// Keyed by both the OS user and the sidecar's configured port so that multiple
+    /// `SidecarManager` instances (e.g. on different ports) each get their own socket
+    /// instead of stealing one another's.
+    fn socket_path(manager: &SidecarManager) -> PathBuf {
+        std::env::temp_dir().join(socket_file_name(unsafe { libc::getuid() }, manager.config.port))
+    }
+
+    fn socket_file_name(uid: u32, port: u16) -> String {
+        format!("ttai-sidecar-{}-{}.sock", uid, port)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::socket_file_name;
+
+        #[test]
+        fn socket_file_name_differs_per_port() {
+            assert_ne!(socket_file_name(501, 8080), socket_file_name(501, 8081));
+        }
+
+        #[test]
+        fn socket_file_name_differs_per_user() {
+            assert_ne!(socket_file_name(501, 8080), socket_file_name(502, 8080));
+        }
+    }
+
+    /// Only honor connections from a peer running as the same Unix user as this process.
+    fn is_same_user(stream: &UnixStream) -> bool {
+        match stream.peer_cred() {
+            Ok(cred) => cred.uid() == unsafe { libc::getuid() },
+            Err(e) => {
+                log::warn!("Control socket: could not read peer credentials: {}", e);
+                false
+            }
+        }
+    }
+
+    pub async fn run(manager: SidecarManager, mut shutdown_rx: oneshot::Receiver<()>) {
+        let path = socket_path(&manager);
+        let _ = std::fs::remove_file(&path);
+
+        let listener = match UnixListener::bind(&path) {
+            Ok(l) => l,
+            Err(e) => {
+                log::error!("Control socket: failed to bind {:?}: {}", path, e);
+                return;
+            }
+        };
+
+        log::info!("Control socket listening on {:?}", path);
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown_rx => break,
+                accepted = listener.accept() => {
+                    let Ok((stream, _)) = accepted else { continue };
+                    if !is_same_user(&stream) {
+                        log::warn!("Control socket: rejected connection from a different user");
+                        continue;
+                    }
+                    tokio::spawn(serve_client(manager.clone(), stream));
+                }
+            }
+        }
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    async fn serve_client(manager: SidecarManager, stream: UnixStream) {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        while let Ok(Some(line)) = lines.next_line().await {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = handle_line(&manager, &line).await;
+            let mut payload = response.to_string();
+            payload.push('\n');
+            if writer.write_all(payload.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::handle_line;
+    use crate::sidecar::SidecarManager;
+    use std::os::windows::io::AsRawHandle;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::windows::named_pipe::{NamedPipeServer, ServerOptions};
+    use tokio::sync::oneshot;
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::Security::{
+        EqualSid, GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER,
+    };
+    use windows_sys::Win32::System::Pipes::GetNamedPipeClientProcessId;
+    use windows_sys::Win32::System::Threading::{
+        GetCurrentProcess, OpenProcess, OpenProcessToken, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    /// Keyed by the sidecar's configured port so that multiple `SidecarManager` instances
+    /// (e.g. on different ports) each get their own pipe instead of one failing to bind.
+    fn pipe_name(manager: &SidecarManager) -> String {
+        pipe_name_for_port(manager.config.port)
+    }
+
+    fn pipe_name_for_port(port: u16) -> String {
+        format!(r"\\.\pipe\ttai-sidecar-{}", port)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::pipe_name_for_port;
+
+        #[test]
+        fn pipe_name_differs_per_port() {
+            assert_ne!(pipe_name_for_port(8080), pipe_name_for_port(8081));
+        }
+    }
+
+    /// Read the SID for a process token's owner into `buf`, returning a pointer into it.
+    unsafe fn token_user_sid(token: HANDLE, buf: &mut Vec<u8>) -> Option<*const core::ffi::c_void> {
+        let mut needed = 0u32;
+        GetTokenInformation(token, TokenUser, std::ptr::null_mut(), 0, &mut needed);
+        if needed == 0 {
+            return None;
+        }
+        buf.resize(needed as usize, 0);
+        if GetTokenInformation(token, TokenUser, buf.as_mut_ptr() as _, needed, &mut needed) == 0 {
+            return None;
+        }
+        Some((*(buf.as_ptr() as *const TOKEN_USER)).User.Sid as *const _)
+    }
+
+    /// Only honor connections from a client process owned by the same Windows user.
+    fn is_same_user(pipe: &NamedPipeServer) -> bool {
+        unsafe {
+            let mut client_pid = 0u32;
+            if GetNamedPipeClientProcessId(pipe.as_raw_handle() as HANDLE, &mut client_pid) == 0 {
+                return false;
+            }
+
+            let client_process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, client_pid);
+            if client_process == 0 {
+                return false;
+            }
+
+            let mut client_token: HANDLE = 0;
+            let opened = OpenProcessToken(client_process, TOKEN_QUERY, &mut client_token);
+            CloseHandle(client_process);
+            if opened == 0 {
+                return false;
+            }
+
+            let mut self_token: HANDLE = 0;
+            let self_opened = OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut self_token);
+
+            let mut client_buf = Vec::new();
+            let mut self_buf = Vec::new();
+            let same = self_opened != 0
+                && matches!(
+                    (
+                        token_user_sid(client_token, &mut client_buf),
+                        token_user_sid(self_token, &mut self_buf),
+                    ),
+                    (Some(a), Some(b)) if EqualSid(a as _, b as _) != 0
+                );
+
+            CloseHandle(client_token);
+            if self_opened != 0 {
+                CloseHandle(self_token);
+            }
+
+            same
+        }
+    }
+
+    pub async fn run(manager: SidecarManager, mut shutdown_rx: oneshot::Receiver<()>) {
+        let pipe_name = pipe_name(&manager);
+        log::info!("Control socket listening on {}", pipe_name);
+
+        loop {
+            let mut server = match ServerOptions::new().create(&pipe_name) {
+                Ok(s) => s,
+                Err(e) => {
+                    log::error!("Control socket: failed to create named pipe: {}", e);
+                    return;
+                }
+            };
+
+            tokio::select! {
+                _ = &mut shutdown_rx => break,
+                connected = server.connect() => {
+                    if connected.is_err() {
+                        continue;
+                    }
+                    if !is_same_user(&server) {
+                        log::warn!("Control socket: rejected connection from a different user");
+                        continue;
+                    }
+                    tokio::spawn(serve_client(manager.clone(), server));
+                }
+            }
+        }
+    }
+
+    async fn serve_client(manager: SidecarManager, pipe: NamedPipeServer) {
+        let (reader, mut writer) = tokio::io::split(pipe);
+        let mut lines = BufReader::new(reader).lines();
+
+        while let Ok(Some(line)) = lines.next_line().await {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = handle_line(&manager, &line).await;
+            let mut payload = response.to_string();
+            payload.push('\n');
+            if writer.write_all(payload.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// Spawn the control socket listener. Send on the returned channel (or drop it) to stop it.
+pub fn spawn(manager: SidecarManager) -> oneshot::Sender<()> {
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    tauri::async_runtime::spawn(imp::run(manager, shutdown_rx));
+    shutdown_tx
+}
@@ -6,7 +6,7 @@ use std::sync::Arc;
 use tauri::Manager;
 use tokio::sync::Mutex;
 use ttai_lib::commands::AppState;
-use ttai_lib::sidecar::SidecarManager;
+use ttai_lib::sidecar::{SidecarConfig, SidecarManager};
 
 fn main() {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
@@ -30,7 +30,8 @@ fn main() {
 
             log::info!("Python path: {:?}", python_path);
 
-            let manager = SidecarManager::new(python_path);
+            let manager =
+                SidecarManager::new(python_path, app.handle().clone(), SidecarConfig::default());
             let state: AppState = Arc::new(Mutex::new(manager));
 
             app.manage(state.clone());
@@ -61,7 +62,7 @@ fn main() {
                 let state_clone = state.inner().clone();
                 tauri::async_runtime::block_on(async move {
                     let manager = state_clone.lock().await;
-                    if let Err(e) = manager.stop() {
+                    if let Err(e) = manager.stop().await {
                         log::error!("Failed to stop server: {}", e);
                     }
                 });
@@ -76,6 +77,11 @@ fn main() {
             ttai_lib::commands::mcp_login,
             ttai_lib::commands::mcp_logout,
             ttai_lib::commands::mcp_get_auth_status,
+            ttai_lib::commands::get_server_status,
+            ttai_lib::commands::mcp_get_logs,
+            ttai_lib::commands::mcp_clear_logs,
+            ttai_lib::commands::mcp_call_tool,
+            ttai_lib::commands::mcp_cancel_tool,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
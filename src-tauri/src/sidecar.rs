@@ -1,8 +1,144 @@
-use serde::Deserialize;
+#[path = "control_socket.rs"]
+mod control_socket;
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::oneshot;
+use tokio::time::Instant;
+
+/// Maximum number of log lines retained per sidecar, oldest lines are dropped first.
+const MAX_LOG_LINES: usize = 1000;
+
+/// How often the supervisor polls the child process and health endpoint.
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Delay after the first failed restart attempt, doubled after each further failure.
+/// The first restart attempt after a crash is made without this delay.
+const RESTART_BACKOFF_INITIAL: Duration = Duration::from_millis(250);
+/// Upper bound on the restart backoff delay.
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// Consecutive restart failures before the supervisor gives up.
+const MAX_CONSECUTIVE_FAILURES: u32 = 6;
+/// How long the server must stay healthy before the backoff state is reset.
+const SUSTAINED_HEALTHY_PERIOD: Duration = Duration::from_secs(60);
+/// Upper bound on the backoff delay between retried auth requests.
+const AUTH_RETRY_BACKOFF_MAX: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerState {
+    Starting,
+    Ready,
+    Restarting,
+    Failed,
+}
+
+/// Configuration for a `SidecarManager`. A `Duration::ZERO` timeout means "wait indefinitely".
+#[derive(Debug, Clone)]
+pub struct SidecarConfig {
+    pub host: String,
+    pub port: u16,
+    pub health_check_timeout: Duration,
+    pub auth_timeout: Duration,
+    pub login_timeout: Duration,
+    pub readiness_attempts: u32,
+    pub readiness_delay: Duration,
+    /// Number of attempts for `login`/`get_auth_status` on connection failures and 5xx responses.
+    pub auth_retry_attempts: u32,
+    /// Initial delay between retried auth requests, doubled after each attempt.
+    pub auth_retry_backoff: Duration,
+}
+
+impl Default for SidecarConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 8080,
+            health_check_timeout: Duration::from_secs(2),
+            auth_timeout: Duration::from_secs(5),
+            login_timeout: Duration::from_secs(30),
+            readiness_attempts: 50,
+            readiness_delay: Duration::from_millis(100),
+            auth_retry_attempts: 3,
+            auth_retry_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Apply a timeout to a request builder, treating a zero duration as "no timeout".
+fn with_timeout(builder: reqwest::RequestBuilder, timeout: Duration) -> reqwest::RequestBuilder {
+    if timeout.is_zero() {
+        builder
+    } else {
+        builder.timeout(timeout)
+    }
+}
+
+/// What the supervisor's poll loop should do next, decided purely from observed state so the
+/// "stopped on purpose" vs. "respawn left the child slot empty" distinction can be tested
+/// without a real child process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SupervisorAction {
+    StopSupervising,
+    ChildAlive,
+    NeedsRestart,
+}
+
+/// `stopping` takes priority over everything else: an intentional `stop()`/`Drop` clears the
+/// child slot too, and must not be mistaken for a failed respawn that also left it empty.
+fn classify_supervisor_poll(
+    stopping: bool,
+    child_slot_occupied: bool,
+    child_still_running: bool,
+) -> SupervisorAction {
+    if stopping {
+        SupervisorAction::StopSupervising
+    } else if child_slot_occupied && child_still_running {
+        SupervisorAction::ChildAlive
+    } else {
+        SupervisorAction::NeedsRestart
+    }
+}
+
+/// Double `current`, capped at `max` — the shared exponential-backoff step used for both
+/// sidecar restarts and retried HTTP requests.
+fn next_backoff(current: Duration, max: Duration) -> Duration {
+    (current * 2).min(max)
+}
+
+/// Whether an HTTP response status is worth retrying (a transient server-side failure),
+/// as opposed to a structured application-level failure that retrying won't fix.
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error()
+}
+
+/// Drain complete `\n\n`-terminated SSE frames from `buf`, returning the parsed JSON payload
+/// of each `data:` line found. Any partial frame at the end of `buf` is left for the next call.
+fn drain_sse_events(buf: &mut String) -> Vec<Value> {
+    let mut events = Vec::new();
+
+    while let Some(boundary) = buf.find("\n\n") {
+        let raw_event: String = buf.drain(..boundary + 2).collect();
+
+        for line in raw_event.lines() {
+            let Some(data) = line.strip_prefix("data:") else { continue };
+            if let Ok(event) = serde_json::from_str::<Value>(data.trim()) {
+                events.push(event);
+            }
+        }
+    }
+
+    events
+}
 
 #[derive(Debug, Deserialize)]
 pub struct AuthStatus {
@@ -27,15 +163,61 @@ pub struct SidecarManager {
     python_path: PathBuf,
     http_client: reqwest::Client,
     base_url: String,
+    config: SidecarConfig,
+    app_handle: AppHandle,
+    logs: Arc<Mutex<VecDeque<String>>>,
+    status: Arc<Mutex<ServerState>>,
+    next_tool_call_id: Arc<AtomicU64>,
+    tool_call_cancellations: Arc<Mutex<HashMap<String, oneshot::Sender<()>>>>,
+    control_socket_shutdown: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    /// Set by `stop()`/`Drop` before `self.child` is cleared, so the supervisor can tell
+    /// "shut down on purpose" apart from "respawn failed and left the child slot empty".
+    stopping: Arc<AtomicBool>,
+    /// True only for the instance created by `new()` (e.g. the one held in `AppState`).
+    /// Clones are handed out to background tasks and short-lived commands that share the
+    /// same underlying child/state via the `Arc`s above but must never tear them down
+    /// themselves on drop — only the primary instance's `Drop` does that.
+    is_primary: bool,
+}
+
+impl Clone for SidecarManager {
+    fn clone(&self) -> Self {
+        Self {
+            child: self.child.clone(),
+            python_path: self.python_path.clone(),
+            http_client: self.http_client.clone(),
+            base_url: self.base_url.clone(),
+            config: self.config.clone(),
+            app_handle: self.app_handle.clone(),
+            logs: self.logs.clone(),
+            status: self.status.clone(),
+            next_tool_call_id: self.next_tool_call_id.clone(),
+            tool_call_cancellations: self.tool_call_cancellations.clone(),
+            control_socket_shutdown: self.control_socket_shutdown.clone(),
+            stopping: self.stopping.clone(),
+            is_primary: false,
+        }
+    }
 }
 
 impl SidecarManager {
-    pub fn new(python_path: PathBuf) -> Self {
+    pub fn new(python_path: PathBuf, app_handle: AppHandle, config: SidecarConfig) -> Self {
+        let base_url = format!("http://{}:{}", config.host, config.port);
+
         Self {
             child: Arc::new(Mutex::new(None)),
             python_path,
             http_client: reqwest::Client::new(),
-            base_url: "http://localhost:8080".to_string(),
+            base_url,
+            config,
+            app_handle,
+            logs: Arc::new(Mutex::new(VecDeque::new())),
+            status: Arc::new(Mutex::new(ServerState::Starting)),
+            next_tool_call_id: Arc::new(AtomicU64::new(1)),
+            tool_call_cancellations: Arc::new(Mutex::new(HashMap::new())),
+            control_socket_shutdown: Arc::new(Mutex::new(None)),
+            stopping: Arc::new(AtomicBool::new(false)),
+            is_primary: true,
         }
     }
 
@@ -46,9 +228,28 @@ impl SidecarManager {
             return Err("Server already running".to_string());
         }
 
+        self.set_status(ServerState::Starting);
+        self.stopping.store(false, Ordering::SeqCst);
+
+        let child = self.spawn_child()?;
+        *child_guard = Some(child);
+        drop(child_guard);
+
+        tauri::async_runtime::spawn(self.clone().supervise());
+
+        let shutdown_tx = control_socket::spawn(self.clone());
+        if let Ok(mut guard) = self.control_socket_shutdown.lock() {
+            *guard = Some(shutdown_tx);
+        }
+
+        Ok(())
+    }
+
+    /// Spawn the `uv run` child process and wire its piped stdout/stderr into the log buffer.
+    fn spawn_child(&self) -> Result<Child, String> {
         log::info!("Starting Python MCP server at {:?}", self.python_path);
 
-        let child = Command::new("uv")
+        let mut child = Command::new("uv")
             .args([
                 "run",
                 "python",
@@ -57,28 +258,177 @@ impl SidecarManager {
                 "--transport",
                 "sse",
                 "--port",
-                "8080",
+                &self.config.port.to_string(),
             ])
             .current_dir(&self.python_path)
             .stdin(Stdio::null())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
             .spawn()
             .map_err(|e| format!("Failed to spawn Python server: {}", e))?;
 
-        *child_guard = Some(child);
+        if let Some(stdout) = child.stdout.take() {
+            self.spawn_log_reader(stdout, "stdout");
+        }
+        if let Some(stderr) = child.stderr.take() {
+            self.spawn_log_reader(stderr, "stderr");
+        }
 
-        Ok(())
+        Ok(child)
+    }
+
+    /// Background task that detects a dead or unhealthy child and restarts it with backoff.
+    async fn supervise(self) {
+        let mut backoff = RESTART_BACKOFF_INITIAL;
+        let mut consecutive_failures = 0u32;
+        let mut healthy_since = Instant::now();
+
+        loop {
+            tokio::time::sleep(SUPERVISOR_POLL_INTERVAL).await;
+
+            let stopping = self.stopping.load(Ordering::SeqCst);
+            let (child_slot_occupied, child_still_running) = match self.child.lock() {
+                Ok(mut guard) => match guard.as_mut() {
+                    Some(child) => (true, matches!(child.try_wait(), Ok(None))),
+                    None => (false, false),
+                },
+                Err(_) => break,
+            };
+
+            let child_alive = match classify_supervisor_poll(stopping, child_slot_occupied, child_still_running) {
+                SupervisorAction::StopSupervising => break,
+                SupervisorAction::ChildAlive => true,
+                SupervisorAction::NeedsRestart => false,
+            };
+
+            if child_alive && self.health_check().await.is_ok() {
+                if healthy_since.elapsed() >= SUSTAINED_HEALTHY_PERIOD {
+                    backoff = RESTART_BACKOFF_INITIAL;
+                    consecutive_failures = 0;
+                }
+                continue;
+            }
+
+            log::warn!("Sidecar crashed or stopped responding, restarting");
+            self.set_status(ServerState::Restarting);
+            let _ = self.app_handle.emit("sidecar-restarting", serde_json::json!({}));
+
+            if let Ok(mut guard) = self.child.lock() {
+                if let Some(mut child) = guard.take() {
+                    let _ = child.start_kill();
+                }
+            }
+
+            match self.restart_child().await {
+                Ok(()) => {
+                    log::info!("Sidecar restarted successfully");
+                    // Don't reset `consecutive_failures`/`backoff` here: a crash-loop that dies
+                    // again before the next poll would otherwise always look like a fresh
+                    // attempt #1, and `MAX_CONSECUTIVE_FAILURES` would never be reached. They
+                    // only reset once the steady-state branch above observes
+                    // `SUSTAINED_HEALTHY_PERIOD` of uptime.
+                    healthy_since = Instant::now();
+                    self.set_status(ServerState::Ready);
+                }
+                Err(e) => {
+                    consecutive_failures += 1;
+                    log::error!(
+                        "Sidecar restart attempt {} failed: {}",
+                        consecutive_failures,
+                        e
+                    );
+
+                    if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+                        self.set_status(ServerState::Failed);
+                        let _ = self
+                            .app_handle
+                            .emit("sidecar-crashed", serde_json::json!({ "error": e }));
+                        break;
+                    }
+
+                    tokio::time::sleep(backoff).await;
+                    backoff = next_backoff(backoff, RESTART_BACKOFF_MAX);
+                }
+            }
+        }
+    }
+
+    async fn restart_child(&self) -> Result<(), String> {
+        let child = self.spawn_child()?;
+        {
+            let mut guard = self.child.lock().map_err(|e| e.to_string())?;
+            *guard = Some(child);
+        }
+        self.wait_for_ready().await
+    }
+
+    fn set_status(&self, state: ServerState) {
+        if let Ok(mut status) = self.status.lock() {
+            *status = state;
+        }
+    }
+
+    /// Current supervisor-tracked server state.
+    pub fn get_status(&self) -> ServerState {
+        self.status
+            .lock()
+            .map(|s| *s)
+            .unwrap_or(ServerState::Failed)
+    }
+
+    /// Read lines from a piped child stream into the log buffer, emitting each as it arrives.
+    fn spawn_log_reader<R>(&self, stream: R, stream_name: &'static str)
+    where
+        R: tokio::io::AsyncRead + Unpin + Send + 'static,
+    {
+        let logs = self.logs.clone();
+        let app_handle = self.app_handle.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let mut lines = BufReader::new(stream).lines();
+
+            while let Ok(Some(line)) = lines.next_line().await {
+                log::debug!("[sidecar:{}] {}", stream_name, line);
+
+                if let Ok(mut buf) = logs.lock() {
+                    if buf.len() >= MAX_LOG_LINES {
+                        buf.pop_front();
+                    }
+                    buf.push_back(line.clone());
+                }
+
+                let _ = app_handle.emit(
+                    "sidecar-log",
+                    serde_json::json!({ "stream": stream_name, "line": line }),
+                );
+            }
+        });
+    }
+
+    /// Return the currently buffered sidecar log lines, oldest first.
+    pub fn get_logs(&self) -> Vec<String> {
+        self.logs
+            .lock()
+            .map(|buf| buf.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Clear the buffered sidecar log lines.
+    pub fn clear_logs(&self) {
+        if let Ok(mut buf) = self.logs.lock() {
+            buf.clear();
+        }
     }
 
     /// Wait for the HTTP server to be ready
     pub async fn wait_for_ready(&self) -> Result<(), String> {
-        let max_attempts = 50;
-        let delay = Duration::from_millis(100);
+        let max_attempts = self.config.readiness_attempts;
+        let delay = self.config.readiness_delay;
 
         for attempt in 0..max_attempts {
             if self.health_check().await.is_ok() {
                 log::info!("MCP server ready after {} attempts", attempt + 1);
+                self.set_status(ServerState::Ready);
                 return Ok(());
             }
             tokio::time::sleep(delay).await;
@@ -91,10 +441,7 @@ impl SidecarManager {
     pub async fn health_check(&self) -> Result<(), String> {
         let url = format!("{}/api/health", self.base_url);
 
-        let response = self
-            .http_client
-            .get(&url)
-            .timeout(Duration::from_secs(2))
+        let response = with_timeout(self.http_client.get(&url), self.config.health_check_timeout)
             .send()
             .await
             .map_err(|e| format!("Health check failed: {}", e))?;
@@ -106,17 +453,13 @@ impl SidecarManager {
         }
     }
 
-    /// Get authentication status
+    /// Get authentication status, retrying transient connection/5xx failures.
     pub async fn get_auth_status(&self) -> Result<AuthStatus, String> {
         let url = format!("{}/api/auth-status", self.base_url);
 
         let response = self
-            .http_client
-            .get(&url)
-            .timeout(Duration::from_secs(5))
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .send_with_retry(|| with_timeout(self.http_client.get(&url), self.config.auth_timeout))
+            .await?;
 
         response
             .json()
@@ -124,7 +467,8 @@ impl SidecarManager {
             .map_err(|e| format!("Failed to parse response: {}", e))
     }
 
-    /// Login to TastyTrade
+    /// Login to TastyTrade, retrying transient connection/5xx failures. A structured
+    /// `LoginResponse { success: false }` is a real auth rejection and is never retried.
     pub async fn login(
         &self,
         client_secret: &str,
@@ -134,17 +478,17 @@ impl SidecarManager {
         let url = format!("{}/api/login", self.base_url);
 
         let response = self
-            .http_client
-            .post(&url)
-            .json(&serde_json::json!({
-                "client_secret": client_secret,
-                "refresh_token": refresh_token,
-                "remember_me": remember_me,
-            }))
-            .timeout(Duration::from_secs(30))
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .send_with_retry(|| {
+                with_timeout(
+                    self.http_client.post(&url).json(&serde_json::json!({
+                        "client_secret": client_secret,
+                        "refresh_token": refresh_token,
+                        "remember_me": remember_me,
+                    })),
+                    self.config.login_timeout,
+                )
+            })
+            .await?;
 
         response
             .json()
@@ -152,20 +496,56 @@ impl SidecarManager {
             .map_err(|e| format!("Failed to parse response: {}", e))
     }
 
+    /// Send a request built by `build`, retrying on connection-level failures and 5xx
+    /// responses with exponential backoff. Any other response (including a 2xx carrying a
+    /// structured failure payload) is returned immediately without retrying.
+    async fn send_with_retry<F>(&self, mut build: F) -> Result<reqwest::Response, String>
+    where
+        F: FnMut() -> reqwest::RequestBuilder,
+    {
+        let attempts = self.config.auth_retry_attempts.max(1);
+        let mut backoff = self.config.auth_retry_backoff;
+        let mut last_err = String::new();
+
+        for attempt in 0..attempts {
+            match build().send().await {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    last_err = format!("Server error: {}", response.status());
+                }
+                Ok(response) => return Ok(response),
+                Err(e) => {
+                    last_err = format!("Request failed: {}", e);
+                }
+            }
+
+            if attempt + 1 < attempts {
+                log::warn!(
+                    "Auth request attempt {} failed ({}), retrying in {:?}",
+                    attempt + 1,
+                    last_err,
+                    backoff
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = next_backoff(backoff, AUTH_RETRY_BACKOFF_MAX);
+            }
+        }
+
+        Err(last_err)
+    }
+
     /// Logout from TastyTrade
     pub async fn logout(&self, clear_credentials: bool) -> Result<LogoutResponse, String> {
         let url = format!("{}/api/logout", self.base_url);
 
-        let response = self
-            .http_client
-            .post(&url)
-            .json(&serde_json::json!({
+        let response = with_timeout(
+            self.http_client.post(&url).json(&serde_json::json!({
                 "clear_credentials": clear_credentials,
-            }))
-            .timeout(Duration::from_secs(5))
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            })),
+            self.config.auth_timeout,
+        )
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
 
         response
             .json()
@@ -173,13 +553,133 @@ impl SidecarManager {
             .map_err(|e| format!("Failed to parse response: {}", e))
     }
 
-    pub fn stop(&self) -> Result<(), String> {
+    /// Invoke an MCP tool by name. Streaming tools (`text/event-stream` responses) have each
+    /// parsed SSE event emitted live as a `mcp-tool-event` Tauri event tagged with the request
+    /// id, while this still returns the final aggregated result once the stream ends.
+    pub async fn call_tool(&self, name: &str, arguments: Value) -> Result<Value, String> {
+        let request_id = format!("tool-{}", self.next_tool_call_id.fetch_add(1, Ordering::Relaxed));
+
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        if let Ok(mut cancellations) = self.tool_call_cancellations.lock() {
+            cancellations.insert(request_id.clone(), cancel_tx);
+        }
+
+        let result = self.call_tool_inner(&request_id, name, arguments, cancel_rx).await;
+
+        if let Ok(mut cancellations) = self.tool_call_cancellations.lock() {
+            cancellations.remove(&request_id);
+        }
+
+        result.map(|value| serde_json::json!({ "request_id": request_id, "result": value }))
+    }
+
+    /// Drop the in-flight stream for a tool call started by `call_tool`, if still running.
+    pub fn cancel_tool(&self, request_id: &str) -> bool {
+        self.tool_call_cancellations
+            .lock()
+            .ok()
+            .and_then(|mut cancellations| cancellations.remove(request_id))
+            .map(|cancel_tx| cancel_tx.send(()).is_ok())
+            .unwrap_or(false)
+    }
+
+    async fn call_tool_inner(
+        &self,
+        request_id: &str,
+        name: &str,
+        arguments: Value,
+        mut cancel_rx: oneshot::Receiver<()>,
+    ) -> Result<Value, String> {
+        let url = format!("{}/api/tools/call", self.base_url);
+
+        let send_fut = with_timeout(
+            self.http_client.post(&url).json(&serde_json::json!({
+                "name": name,
+                "arguments": arguments,
+            })),
+            Duration::ZERO,
+        )
+        .send();
+
+        // A hung upstream can sit in this request/response-headers phase indefinitely, so the
+        // cancel path has to apply here too, not just once streaming starts.
+        let response = tokio::select! {
+            _ = &mut cancel_rx => return Err("Tool call cancelled".to_string()),
+            result = send_fut => result.map_err(|e| format!("Request failed: {}", e))?,
+        };
+
+        let is_stream = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|ct| ct.starts_with("text/event-stream"));
+
+        if is_stream {
+            self.stream_tool_events(request_id, response, cancel_rx).await
+        } else {
+            response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        }
+    }
+
+    /// Consume an SSE response, emitting each `data:` event and returning the last
+    /// `{"type":"result"}` event's payload (or all collected events if none was sent).
+    async fn stream_tool_events(
+        &self,
+        request_id: &str,
+        response: reqwest::Response,
+        mut cancel_rx: oneshot::Receiver<()>,
+    ) -> Result<Value, String> {
+        let mut byte_stream = response.bytes_stream();
+        let mut buf = String::new();
+        let mut events = Vec::new();
+        let mut final_result = None;
+
+        loop {
+            tokio::select! {
+                _ = &mut cancel_rx => {
+                    return Err("Tool call cancelled".to_string());
+                }
+                chunk = byte_stream.next() => {
+                    let Some(chunk) = chunk else { break };
+                    let chunk = chunk.map_err(|e| format!("Stream error: {}", e))?;
+                    buf.push_str(&String::from_utf8_lossy(&chunk));
+
+                    for event in drain_sse_events(&mut buf) {
+                        let _ = self.app_handle.emit(
+                            "mcp-tool-event",
+                            serde_json::json!({ "request_id": request_id, "event": event }),
+                        );
+
+                        if event.get("type").and_then(Value::as_str) == Some("result") {
+                            final_result = event.get("data").cloned();
+                        }
+                        events.push(event);
+                    }
+                }
+            }
+        }
+
+        Ok(final_result.unwrap_or_else(|| serde_json::json!({ "events": events })))
+    }
+
+    pub async fn stop(&self) -> Result<(), String> {
+        self.stopping.store(true, Ordering::SeqCst);
+
+        if let Ok(mut guard) = self.control_socket_shutdown.lock() {
+            if let Some(shutdown_tx) = guard.take() {
+                let _ = shutdown_tx.send(());
+            }
+        }
+
         let mut child_guard = self.child.lock().map_err(|e| e.to_string())?;
 
         if let Some(mut child) = child_guard.take() {
             log::info!("Stopping Python MCP server");
-            let _ = child.kill();
-            let _ = child.wait();
+            let _ = child.kill().await;
+            let _ = child.wait().await;
         }
 
         Ok(())
@@ -196,6 +696,130 @@ impl SidecarManager {
 
 impl Drop for SidecarManager {
     fn drop(&mut self) {
-        let _ = self.stop();
+        // Clones (the supervisor's, a tool call's, the control socket's) share the same
+        // `Arc`s but must never tear the sidecar down just because one of them went out of
+        // scope — only the primary instance's drop (e.g. `AppState` being torn down, whether
+        // via an explicit `stop()` or an abnormal exit) does that.
+        if !self.is_primary {
+            return;
+        }
+
+        self.stopping.store(true, Ordering::SeqCst);
+
+        if let Ok(mut guard) = self.control_socket_shutdown.lock() {
+            if let Some(shutdown_tx) = guard.take() {
+                let _ = shutdown_tx.send(());
+            }
+        }
+
+        if let Ok(mut child_guard) = self.child.lock() {
+            if let Some(mut child) = child_guard.take() {
+                let _ = child.start_kill();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_supervisor_poll_stops_when_stopping_is_set() {
+        // Even though the child slot looks empty exactly like a failed respawn would leave it,
+        // `stopping` must win so an intentional `stop()` doesn't get treated as a crash.
+        assert_eq!(
+            classify_supervisor_poll(true, false, false),
+            SupervisorAction::StopSupervising
+        );
+        assert_eq!(
+            classify_supervisor_poll(true, true, true),
+            SupervisorAction::StopSupervising
+        );
+    }
+
+    #[test]
+    fn classify_supervisor_poll_needs_restart_when_respawn_left_the_slot_empty() {
+        // This is the bug this function exists to prevent: a failed `restart_child` leaves
+        // `self.child` at `None` without `stopping` ever being set, and that must count as
+        // "needs another restart attempt", not "supervisor should stop".
+        assert_eq!(
+            classify_supervisor_poll(false, false, false),
+            SupervisorAction::NeedsRestart
+        );
+    }
+
+    #[test]
+    fn classify_supervisor_poll_needs_restart_when_child_exited() {
+        assert_eq!(
+            classify_supervisor_poll(false, true, false),
+            SupervisorAction::NeedsRestart
+        );
+    }
+
+    #[test]
+    fn classify_supervisor_poll_alive_when_child_slot_occupied_and_running() {
+        assert_eq!(
+            classify_supervisor_poll(false, true, true),
+            SupervisorAction::ChildAlive
+        );
+    }
+
+    #[test]
+    fn next_backoff_doubles_up_to_the_cap() {
+        let max = Duration::from_secs(30);
+        assert_eq!(next_backoff(Duration::from_millis(250), max), Duration::from_millis(500));
+        assert_eq!(next_backoff(Duration::from_secs(20), max), max);
+        assert_eq!(next_backoff(max, max), max);
+    }
+
+    #[test]
+    fn is_retryable_status_is_true_only_for_5xx() {
+        assert!(is_retryable_status(reqwest::StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_retryable_status(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(reqwest::StatusCode::OK));
+        assert!(!is_retryable_status(reqwest::StatusCode::BAD_REQUEST));
+        assert!(!is_retryable_status(reqwest::StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn drain_sse_events_parses_a_complete_frame() {
+        let mut buf = "data: {\"type\":\"progress\",\"data\":1}\n\n".to_string();
+        let events = drain_sse_events(&mut buf);
+
+        assert_eq!(events, vec![serde_json::json!({"type": "progress", "data": 1})]);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn drain_sse_events_leaves_a_partial_frame_for_next_call() {
+        let mut buf = "data: {\"type\":\"a\"}\n\ndata: {\"type".to_string();
+        let events = drain_sse_events(&mut buf);
+
+        assert_eq!(events, vec![serde_json::json!({"type": "a"})]);
+        assert_eq!(buf, "data: {\"type");
+    }
+
+    #[test]
+    fn drain_sse_events_ignores_non_data_lines_and_bad_json() {
+        let mut buf = "event: ping\ndata: not json\n\nid: 1\ndata: {\"ok\":true}\n\n".to_string();
+        let events = drain_sse_events(&mut buf);
+
+        assert_eq!(events, vec![serde_json::json!({"ok": true})]);
+    }
+
+    #[test]
+    fn drain_sse_events_handles_multiple_frames_in_one_chunk() {
+        let mut buf = "data: {\"n\":1}\n\ndata: {\"n\":2}\n\ndata: {\"n\":3}\n\n".to_string();
+        let events = drain_sse_events(&mut buf);
+
+        assert_eq!(
+            events,
+            vec![
+                serde_json::json!({"n": 1}),
+                serde_json::json!({"n": 2}),
+                serde_json::json!({"n": 3}),
+            ]
+        );
     }
 }